@@ -5,5 +5,6 @@ interactive terminal visualizations.
 """
 
 from .app import SketchApp
+from .state import load_state, save_state
 
-__all__ = ["SketchApp"]
+__all__ = ["SketchApp", "load_state", "save_state"]
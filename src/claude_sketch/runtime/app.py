@@ -4,16 +4,39 @@ All sketches inherit from SketchApp, which provides sensible defaults
 and common functionality for interactive terminal visualizations.
 """
 
+import asyncio
+import signal
+import sys
+import traceback
+from pathlib import Path
+from typing import Optional
+
 from textual.app import App
 from textual.binding import Binding
 
 
+def _crash_log_path(script: Path) -> Optional[Path]:
+    """Return `<name>.log` beside a sketch in `.claude-sketch/sketches/`.
+
+    Scripts anywhere else get no crash log, so constructing a SketchApp
+    never touches files outside the sketch directory.
+    """
+    script = script.resolve()
+    if script.suffix != ".py" or script.parent.parts[-2:] != (".claude-sketch", "sketches"):
+        return None
+    return script.with_suffix(".log")
+
+
 class SketchApp(App):
     """Base class for Claude sketches using Textual.
 
     Inherits from textual.App and provides:
-    - Default 'q' and Escape key bindings to quit
+    - Default 'q', Escape and Ctrl+C key bindings to quit
     - Dark mode enabled by default
+    - Clean exit on SIGINT/SIGTERM/SIGHUP so the terminal is restored and
+      on_unmount runs
+    - Crash tracebacks saved next to sketches in .claude-sketch/sketches/
+      as <name>.log
     - CSS support for styling
 
     Usage:
@@ -27,12 +50,17 @@ class SketchApp(App):
 
         if __name__ == "__main__":
             MySketch().run()
+
+    Sketches that need 'q' or Escape for themselves can opt out of the
+    default quit keys with ``class MySketch(SketchApp, inherit_bindings=False)``.
     """
 
     # Default key bindings - sketches can extend this
     BINDINGS = [
         Binding("q", "quit", "Quit", show=True),
         Binding("escape", "quit", "Quit", show=False),
+        # priority so Textual's own ctrl+c (copy) binding doesn't swallow it
+        Binding("ctrl+c", "quit", "Quit", show=False, priority=True),
     ]
 
     # Enable dark mode by default
@@ -50,3 +78,40 @@ class SketchApp(App):
         super().__init__(*args, **kwargs)
         # Force dark mode for consistent appearance
         self.dark = True
+        # The pane closes as soon as the sketch exits, taking Textual's
+        # printed traceback with it, so crashes are also saved to a file
+        self._crash_log = _crash_log_path(Path(sys.argv[0]))
+        self._crashed = False
+
+    def _handle_exception(self, error: Exception) -> None:
+        """Save the traceback to the crash log before Textual exits."""
+        # Private Textual hook every unhandled exception passes through; it
+        # isn't part of the public API, so tests/test_app.py fails loudly if
+        # a Textual upgrade stops routing crashes here
+        self._crashed = True
+        if self._crash_log is not None:
+            try:
+                self._crash_log.write_text("".join(traceback.format_exception(error)))
+            except OSError:
+                pass
+        super()._handle_exception(error)
+
+    def on_unmount(self) -> None:
+        """Remove the previous run's crash log after a clean exit."""
+        if self._crash_log is not None and not self._crashed:
+            self._crash_log.unlink(missing_ok=True)
+
+    def on_mount(self) -> None:
+        """Exit cleanly when signalled so the terminal is restored.
+
+        Textual also calls this when a sketch defines its own on_mount.
+        """
+        loop = asyncio.get_running_loop()
+        # SIGHUP arrives when the pane is closed; Windows has no SIGHUP
+        signals = (signal.SIGINT, signal.SIGTERM, getattr(signal, "SIGHUP", None))
+        for sig in filter(None, signals):
+            try:
+                loop.add_signal_handler(sig, self.exit)
+            except (NotImplementedError, RuntimeError):
+                # Windows event loops and non-main threads don't support this
+                pass
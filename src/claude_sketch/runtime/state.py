@@ -0,0 +1,43 @@
+"""Settings persistence for Claude sketches.
+
+Sketches that let the user tweak values can remember them across runs by
+saving a JSON-serializable state to `.claude-sketch/state/<name>.json`.
+"""
+
+import json
+from pathlib import Path
+from typing import Any
+
+STATE_DIR = Path(".claude-sketch") / "state"
+
+
+def _state_path(name: str) -> Path:
+    # Keep every state file inside STATE_DIR; ':' would make Windows read
+    # a name like "C:foo" as relative to another drive
+    if name in ("", ".", "..") or any(c in name for c in "/\\:"):
+        raise ValueError(f"invalid state name: {name!r}")
+    return STATE_DIR / f"{name}.json"
+
+
+def load_state(name: str, default: Any = None) -> Any:
+    """Load the state saved under `name`.
+
+    Returns `default` if nothing has been saved yet or the file can't be
+    parsed, so a corrupt state file never stops a sketch from starting.
+    Raises `ValueError` if `name` is `..` or contains `/`, `\\` or `:`.
+    """
+    path = _state_path(name)
+    try:
+        return json.loads(path.read_text())
+    except (OSError, ValueError):
+        return default
+
+
+def save_state(name: str, state: Any) -> None:
+    """Save `state` (anything `json.dumps` accepts) under `name`.
+
+    Raises `ValueError` if `name` is `..` or contains `/`, `\\` or `:`.
+    """
+    path = _state_path(name)
+    path.parent.mkdir(parents=True, exist_ok=True)
+    path.write_text(json.dumps(state, indent=2))
@@ -0,0 +1,62 @@
+"""Tests for SketchApp's crash log."""
+
+import os
+import sys
+import tempfile
+import unittest
+from pathlib import Path
+from unittest import mock
+
+from claude_sketch.runtime import SketchApp
+
+
+class CrashingSketch(SketchApp):
+    BINDINGS = [("x", "crash", "Crash")]
+
+    def action_crash(self) -> None:
+        raise RuntimeError("sketch blew up")
+
+
+class CrashLogTest(unittest.IsolatedAsyncioTestCase):
+    def setUp(self) -> None:
+        self._cwd = os.getcwd()
+        self._tmp = tempfile.TemporaryDirectory()
+        os.chdir(self._tmp.name)
+        self.sketches = Path(".claude-sketch", "sketches")
+        self.sketches.mkdir(parents=True)
+
+    def tearDown(self) -> None:
+        os.chdir(self._cwd)
+        self._tmp.cleanup()
+
+    async def _run(self, script: Path, keys: tuple[str, ...]) -> None:
+        with mock.patch.object(sys, "argv", [str(script)]):
+            app = CrashingSketch()
+        try:
+            async with app.run_test() as pilot:
+                await pilot.press(*keys)
+        except RuntimeError:
+            # Depending on the Textual version run_test re-raises the crash
+            pass
+
+    async def test_crash_writes_log(self) -> None:
+        log = self.sketches / "demo.log"
+        log.write_text("previous crash")
+        await self._run(self.sketches / "demo.py", ("x",))
+        self.assertIn("sketch blew up", log.read_text())
+
+    async def test_clean_exit_removes_log(self) -> None:
+        log = self.sketches / "demo.log"
+        log.write_text("previous crash")
+        await self._run(self.sketches / "demo.py", ("q",))
+        self.assertFalse(log.exists())
+
+    async def test_scripts_outside_sketch_dir_get_no_log(self) -> None:
+        log = Path("demo.log")
+        log.write_text("not ours")
+        await self._run(Path("demo.py"), ("x",))
+        self.assertEqual(log.read_text(), "not ours")
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,50 @@
+"""Tests for the load_state/save_state persistence helpers."""
+
+import os
+import tempfile
+import unittest
+from pathlib import Path
+
+from claude_sketch.runtime.state import STATE_DIR, load_state, save_state
+
+
+class StateTest(unittest.TestCase):
+    def setUp(self) -> None:
+        # State paths are relative to the working directory, like a sketch run
+        self._cwd = os.getcwd()
+        self._tmp = tempfile.TemporaryDirectory()
+        os.chdir(self._tmp.name)
+
+    def tearDown(self) -> None:
+        os.chdir(self._cwd)
+        self._tmp.cleanup()
+
+    def test_dict_round_trips(self) -> None:
+        state = {"speed": 7, "colors": ["red", "cyan"], "paused": False}
+        save_state("tuner", state)
+        self.assertTrue(Path(STATE_DIR, "tuner.json").is_file())
+        self.assertEqual(load_state("tuner"), state)
+
+    def test_name_may_contain_double_dots(self) -> None:
+        save_state("v1..2", [1, 2])
+        self.assertEqual(load_state("v1..2"), [1, 2])
+
+    def test_missing_state_returns_default(self) -> None:
+        self.assertEqual(load_state("nothing-saved", {"speed": 5}), {"speed": 5})
+
+    def test_corrupt_file_returns_default(self) -> None:
+        STATE_DIR.mkdir(parents=True)
+        Path(STATE_DIR, "tuner.json").write_text("{not json")
+        self.assertEqual(load_state("tuner", "default"), "default")
+
+    def test_names_cannot_escape_state_dir(self) -> None:
+        for name in ("../x", "a/b", "a\\b", "C:foo", "..", ""):
+            with self.subTest(name=name):
+                with self.assertRaises(ValueError):
+                    save_state(name, {})
+                with self.assertRaises(ValueError):
+                    load_state(name)
+
+
+if __name__ == "__main__":
+    unittest.main()